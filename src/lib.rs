@@ -3,7 +3,21 @@
 //! Point.rs is a library that defines the core Point types for various laser
 //! projection libraries. This standardization makes it easier for library
 //! interop and prevents expensive and unwieldy type conversion.
+//!
+//! `SimplePoint` and `PipelinePoint` are both instantiations of the generic
+//! `Point<Pos, Col>`, so other precisions (e.g. higher-resolution DACs) can
+//! be plugged in without another copy-pasted struct.
+//!
+//! The `std` feature is enabled by default and may be turned off with
+//! `default-features = false` so the crate can run on microcontrollers
+//! driving galvos directly. Point construction, conversion between
+//! precisions, and color arithmetic (saturating add/subtract, scaling and
+//! blending) remain available under `no_std`. Gamma encoding/decoding relies
+//! on `f32::powf` and HSV color-space operations on `f32::rem_euclid`,
+//! neither of which is present on `core::f32`, so those require the `std`
+//! feature.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![deny(dead_code)]
 #![deny(missing_docs)]
 #![deny(unreachable_patterns)]
@@ -11,59 +25,118 @@
 #![deny(unused_imports)]
 #![deny(unused_qualifications)]
 
-use std::fmt;
+#[cfg(feature = "serde")]
+use serde_derive::{Serialize, Deserialize};
 
-/// Core point type.
-/// Supports position (x, y), color (r, g, b), and an is_blank flag.
-#[derive(Clone, Copy, Debug, Default)]
-pub struct SimplePoint {
-  /// X-coordinate.
-  pub x: i16,
-  /// Y-coordinate.
-  pub y: i16,
-  /// Red color value.
-  pub r: u8,
-  /// Green color value.
-  pub g: u8,
-  /// Blue color value.
-  pub b: u8,
-  /// Whether the point is semantically considered a "blanking" point.
-  /// A blanking point may still encode color information, but we generally do
-  /// not render these points unless we're debugging.
-  pub is_blank: bool,
+use core::fmt;
+
+/// A position or color channel's native numeric type, convertible to/from
+/// the `f32` working space used when crossing between point precisions
+/// (e.g. `Point::convert`).
+pub trait Coord: Copy {
+  /// Cast this value into the `f32` working space.
+  fn to_f32(self) -> f32;
+
+  /// Cast an `f32` working-space value into this coordinate's native type.
+  fn from_f32(v: f32) -> Self;
+}
+
+/// Describes a color channel's numeric range, on top of the `Coord`
+/// conversion to/from the `f32` working space. `MIN`/`MAX` are a
+/// color-channel concept (e.g. `0..=255` for `u8`) and must not be relied
+/// on for position types.
+pub trait Component: Coord {
+  /// Minimum representable value.
+  const MIN: Self;
+
+  /// Maximum representable value.
+  const MAX: Self;
+}
+
+impl Coord for i16 {
+  fn to_f32(self) -> f32 { self as f32 }
+  fn from_f32(v: f32) -> i16 { v as i16 }
+}
+
+impl Coord for u8 {
+  fn to_f32(self) -> f32 { self as f32 }
+  fn from_f32(v: f32) -> u8 { v as u8 }
 }
 
-/// Working point type. Do math calculations on this point type.
+impl Component for u8 {
+  const MIN: u8 = 0;
+  const MAX: u8 = 255;
+}
+
+impl Coord for f32 {
+  fn to_f32(self) -> f32 { self }
+  fn from_f32(v: f32) -> f32 { v }
+}
+
+impl Component for f32 {
+  const MIN: f32 = 0.0;
+  const MAX: f32 = 255.0;
+}
+
+impl Coord for i32 {
+  fn to_f32(self) -> f32 { self as f32 }
+  fn from_f32(v: f32) -> i32 { v as i32 }
+}
+
+impl Coord for u16 {
+  fn to_f32(self) -> f32 { self as f32 }
+  fn from_f32(v: f32) -> u16 { v as u16 }
+}
+
+impl Component for u16 {
+  const MIN: u16 = 0;
+  const MAX: u16 = 65_535;
+}
+
+/// Core point type, generic over its position (`Pos`) and color (`Col`)
+/// component types.
 /// Supports position (x, y), color (r, g, b), and an is_blank flag.
+///
+/// With the `serde` feature enabled, this derives `Serialize`/`Deserialize`
+/// with stable field names.
 #[derive(Clone, Copy, Debug, Default)]
-pub struct PipelinePoint {
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Point<Pos, Col> {
   /// X-coordinate.
-  pub x: f32,
+  pub x: Pos,
   /// Y-coordinate.
-  pub y: f32,
+  pub y: Pos,
   /// Red color value.
-  pub r: f32,
+  pub r: Col,
   /// Green color value.
-  pub g: f32,
+  pub g: Col,
   /// Blue color value.
-  pub b: f32,
+  pub b: Col,
   /// Whether the point is semantically considered a "blanking" point.
   /// A blanking point may still encode color information, but we generally do
   /// not render these points unless we're debugging.
   pub is_blank: bool,
 }
 
-impl SimplePoint {
+/// Point type used for sending to the DAC. Coordinates are `i16`, color
+/// channels are `u8`.
+pub type SimplePoint = Point<i16, u8>;
+
+/// Working point type. Do math calculations on this point type. Coordinates
+/// and color channels are `f32`.
+pub type PipelinePoint = Point<f32, f32>;
+
+impl<Pos, Col> Point<Pos, Col> where Pos: Coord, Col: Component {
   /// Minimum value of the color channels.
-  pub const MIN_COLOR : u8 = 0;
+  pub const MIN_COLOR : Col = Col::MIN;
 
   /// Maximum value of the color channels.
-  pub const MAX_COLOR : u8 = 255;
+  pub const MAX_COLOR : Col = Col::MAX;
 
-  /// SimplePoint CTOR.
+  /// Point CTOR.
   /// Lets you specify colors for each channel separately.
-  pub fn xy_rgb(x: i16, y: i16, r: u8, g: u8, b: u8) -> SimplePoint {
-    SimplePoint {
+  pub fn xy_rgb(x: Pos, y: Pos, r: Col, g: Col, b: Col) -> Point<Pos, Col> {
+    Point {
       x: x,
       y: y,
       r: r,
@@ -73,37 +146,25 @@ impl SimplePoint {
     }
   }
 
-  /// SimplePoint CTOR.
+  /// Point CTOR.
   /// Crates a blanking point.
   /// The blanking boolean is set to true, meaning this is semantically
   /// considered to be used for blanking purposes.
-  pub fn xy_blank(x: i16, y: i16) -> SimplePoint {
-    SimplePoint {
+  pub fn xy_blank(x: Pos, y: Pos) -> Point<Pos, Col> {
+    Point {
       x: x,
       y: y,
-      r: 0,
-      g: 0,
-      b: 0,
+      r: Col::MIN,
+      g: Col::MIN,
+      b: Col::MIN,
       is_blank: true,
     }
   }
 
-  /// Transform a SimplePoint into a PipelinePoint for math operations.
-  pub fn into_pipeline_pt(&self) -> PipelinePoint {
-    PipelinePoint {
-      x: self.x as f32,
-      y: self.y as f32,
-      r: self.r as f32,
-      g: self.g as f32,
-      b: self.b as f32,
-      is_blank: self.is_blank,
-    }
-  }
-
-  /// SimplePoint CTOR.
+  /// Point CTOR.
   /// Uses the same intensity value for all color channels.
-  pub fn xy_luma(x: i16, y: i16, luminance: u8) -> SimplePoint {
-    SimplePoint {
+  pub fn xy_luma(x: Pos, y: Pos, luminance: Col) -> Point<Pos, Col> {
+    Point {
       x: x,
       y: y,
       r: luminance,
@@ -113,159 +174,430 @@ impl SimplePoint {
     }
   }
 
-  /// SimplePoint CTOR.
+  /// Point CTOR.
   /// Sets only the red color channel.
-  pub fn xy_red(x: i16, y: i16, red: u8) -> SimplePoint {
-    SimplePoint {
+  pub fn xy_red(x: Pos, y: Pos, red: Col) -> Point<Pos, Col> {
+    Point {
       x: x,
       y: y,
       r: red,
-      g: 0,
-      b: 0,
+      g: Col::MIN,
+      b: Col::MIN,
       is_blank: false,
     }
   }
 
-  /// SimplePoint CTOR.
+  /// Point CTOR.
   /// Sets only the green color channel.
-  pub fn xy_green(x: i16, y: i16, green: u8) -> SimplePoint {
-    SimplePoint {
+  pub fn xy_green(x: Pos, y: Pos, green: Col) -> Point<Pos, Col> {
+    Point {
       x: x,
       y: y,
-      r: 0,
+      r: Col::MIN,
       g: green,
-      b: 0,
+      b: Col::MIN,
       is_blank: false,
     }
   }
 
-  /// SimplePoint CTOR.
+  /// Point CTOR.
   /// Sets only the blue color channel.
-  pub fn xy_blue(x: i16, y: i16, blue: u8) -> SimplePoint {
-    SimplePoint {
+  pub fn xy_blue(x: Pos, y: Pos, blue: Col) -> Point<Pos, Col> {
+    Point {
       x: x,
       y: y,
-      r: 0,
-      g: 0,
+      r: Col::MIN,
+      g: Col::MIN,
       b: blue,
       is_blank: false,
     }
   }
 
-  /// SimplePoint CTOR.
+  /// Point CTOR.
   /// If set to on, the lasers are at full power. Otherwise, they're off.
   /// An "off" point is *not* considered a blanking point.
-  pub fn xy_binary(x: i16, y: i16, on: bool) -> SimplePoint {
-    let c = if on { Self::MAX_COLOR } else { 0 };
-    SimplePoint::xy_rgb(x, y, c, c, c)
+  pub fn xy_binary(x: Pos, y: Pos, on: bool) -> Point<Pos, Col> {
+    let c = if on { Col::MAX } else { Col::MIN };
+    Point::xy_rgb(x, y, c, c, c)
+  }
+
+  /// Convert this point into another precision, casting the position and
+  /// color channels through the shared `f32` working space. `is_blank` is
+  /// preserved. This is what `into_pipeline_pt`/`into_simple_pt` do under
+  /// the hood.
+  pub fn convert<P2: Coord, C2: Component>(&self) -> Point<P2, C2> {
+    Point {
+      x: P2::from_f32(self.x.to_f32()),
+      y: P2::from_f32(self.y.to_f32()),
+      r: C2::from_f32(self.r.to_f32()),
+      g: C2::from_f32(self.g.to_f32()),
+      b: C2::from_f32(self.b.to_f32()),
+      is_blank: self.is_blank,
+    }
   }
 }
 
-impl PipelinePoint {
-  /// Minimum value of the color channels.
-  pub const MIN_COLOR : f32 = 0.0;
+impl SimplePoint {
+  /// Transform a SimplePoint into a PipelinePoint for math operations.
+  pub fn into_pipeline_pt(&self) -> PipelinePoint {
+    self.convert()
+  }
 
-  /// Maximum value of the color channels.
-  pub const MAX_COLOR : f32 = 255.0;
+  /// SimplePoint CTOR.
+  /// Builds a point's color by unpacking a packed `0xRRGGBB` hex value.
+  pub fn xy_hex(x: i16, y: i16, hex: u32) -> SimplePoint {
+    let r = ((hex >> 16) & 0xFF) as u8;
+    let g = ((hex >> 8) & 0xFF) as u8;
+    let b = (hex & 0xFF) as u8;
+    SimplePoint::xy_rgb(x, y, r, g, b)
+  }
 
-  /// PipelinePoint CTOR.
-  /// Lets you specify colors for each channel separately.
-  pub fn xy_rgb(x: f32, y: f32, r: f32, g: f32, b: f32) -> PipelinePoint {
-    PipelinePoint {
-      x: x,
-      y: y,
-      r: r,
-      g: g,
-      b: b,
-      is_blank: false,
+  /// SimplePoint CTOR.
+  /// Parses a hex color string in `"#RRGGBB"` or shorthand `"#RGB"` form
+  /// (the leading `#` is optional). Returns `None` if the string isn't a
+  /// recognized hex color.
+  pub fn from_hex_str(x: i16, y: i16, hex: &str) -> Option<SimplePoint> {
+    let hex = hex.trim_start_matches('#');
+
+    if !hex.bytes().all(|c| c.is_ascii_hexdigit()) {
+      return None;
     }
+
+    let (r, g, b) = match hex.len() {
+      6 => {
+        let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+        let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+        let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+        (r, g, b)
+      }
+      3 => {
+        let r = u8::from_str_radix(&hex[0..1], 16).ok()?;
+        let g = u8::from_str_radix(&hex[1..2], 16).ok()?;
+        let b = u8::from_str_radix(&hex[2..3], 16).ok()?;
+        (r * 17, g * 17, b * 17)
+      }
+      _ => return None,
+    };
+
+    Some(SimplePoint::xy_rgb(x, y, r, g, b))
   }
 
-  /// PipelinePoint CTOR.
-  /// Crates a blanking point.
-  /// The blanking boolean is set to true, meaning this is semantically
-  /// considered to be used for blanking purposes.
-  pub fn xy_blank(x: f32, y: f32) -> PipelinePoint {
-    PipelinePoint {
-      x: x,
-      y: y,
-      r: 0.0,
-      g: 0.0,
-      b: 0.0,
-      is_blank: true,
+  /// Pack this point's color channels into a single `0xRRGGBB` value.
+  pub fn as_hex(&self) -> u32 {
+    ((self.r as u32) << 16) | ((self.g as u32) << 8) | (self.b as u32)
+  }
+
+  /// Add two points' color channels, clamping each channel at `MAX_COLOR`
+  /// instead of wrapping around on overflow. Position (x, y) is taken from
+  /// `self` unchanged.
+  pub fn saturating_add(&self, other: &SimplePoint) -> SimplePoint {
+    SimplePoint {
+      x: self.x,
+      y: self.y,
+      r: self.r.saturating_add(other.r),
+      g: self.g.saturating_add(other.g),
+      b: self.b.saturating_add(other.b),
+      is_blank: self.is_blank,
     }
   }
 
-  /// Transform a PipelinePoint into a SimplePoint for sending to the DAC.
-  pub fn into_simple_pt(&self) -> SimplePoint {
+  /// Subtract `other`'s color channels from `self`'s, clamping each channel
+  /// at `MIN_COLOR` instead of wrapping around on underflow. Position (x, y)
+  /// is taken from `self` unchanged.
+  pub fn saturating_sub(&self, other: &SimplePoint) -> SimplePoint {
     SimplePoint {
-      x: self.x as i16,
-      y: self.y as i16,
-      r: self.r as u8,
-      g: self.g as u8,
-      b: self.b as u8,
+      x: self.x,
+      y: self.y,
+      r: self.r.saturating_sub(other.r),
+      g: self.g.saturating_sub(other.g),
+      b: self.b.saturating_sub(other.b),
       is_blank: self.is_blank,
     }
   }
 
-  /// PipelinePoint CTOR.
-  /// Uses the same intensity value for all color channels.
-  pub fn xy_luma(x: f32, y: f32, luminance: f32) -> PipelinePoint {
-    PipelinePoint {
-      x: x,
-      y: y,
-      r: luminance,
-      g: luminance,
-      b: luminance,
-      is_blank: false,
+  /// Scale each color channel by `factor`, rounding and clamping into
+  /// `[MIN_COLOR, MAX_COLOR]`. Position (x, y) is taken from `self`
+  /// unchanged.
+  pub fn scale(&self, factor: f32) -> SimplePoint {
+    let scale_channel = |c: u8| -> u8 {
+      let scaled = (c as f32 * factor)
+        .max(Self::MIN_COLOR as f32)
+        .min(Self::MAX_COLOR as f32);
+      // Round half up by hand: `f32::round` is not available on `core::f32`.
+      (scaled + 0.5) as u8
+    };
+
+    SimplePoint {
+      x: self.x,
+      y: self.y,
+      r: scale_channel(self.r),
+      g: scale_channel(self.g),
+      b: scale_channel(self.b),
+      is_blank: self.is_blank,
     }
   }
 
+  /// Blend `self` and `other`'s color channels, computing
+  /// `self * (1.0 - alpha) + other * alpha` per channel, with rounding and
+  /// clamping into `[MIN_COLOR, MAX_COLOR]`. Position (x, y) is taken from
+  /// `self` unchanged.
+  pub fn blend(&self, other: &SimplePoint, alpha: f32) -> SimplePoint {
+    let alpha = alpha.max(0.0).min(1.0);
+    let blend_channel = |a: u8, b: u8| -> u8 {
+      let blended = (a as f32 * (1.0 - alpha) + b as f32 * alpha)
+        .max(Self::MIN_COLOR as f32)
+        .min(Self::MAX_COLOR as f32);
+      // Round half up by hand: `f32::round` is not available on `core::f32`.
+      (blended + 0.5) as u8
+    };
+
+    SimplePoint {
+      x: self.x,
+      y: self.y,
+      r: blend_channel(self.r, other.r),
+      g: blend_channel(self.g, other.g),
+      b: blend_channel(self.b, other.b),
+      is_blank: self.is_blank,
+    }
+  }
+}
+
+impl PipelinePoint {
+  /// Transform a PipelinePoint into a SimplePoint for sending to the DAC.
+  pub fn into_simple_pt(&self) -> SimplePoint {
+    self.convert()
+  }
+
   /// PipelinePoint CTOR.
-  /// Sets only the red color channel.
-  pub fn xy_red(x: f32, y: f32, red: f32) -> PipelinePoint {
+  /// Builds a point's color by unpacking a packed `0xRRGGBB` hex value,
+  /// expanding each byte into its float color channel.
+  pub fn xy_hex(x: f32, y: f32, hex: u32) -> PipelinePoint {
+    let r = ((hex >> 16) & 0xFF) as f32;
+    let g = ((hex >> 8) & 0xFF) as f32;
+    let b = (hex & 0xFF) as f32;
+    PipelinePoint::xy_rgb(x, y, r, g, b)
+  }
+
+  /// Gamma-encode the color channels from linear light into sRGB space,
+  /// the space most DACs expect their input in. Channels are normalized by
+  /// `MAX_COLOR`, clamped to `[0, 1]`, passed through the standard sRGB
+  /// transfer function, and scaled back. Position and `is_blank` are left
+  /// untouched.
+  ///
+  /// Requires the `std` feature: the transfer function's exponent is not
+  /// available on `core::f32`.
+  #[cfg(feature = "std")]
+  pub fn encode_srgb(&self) -> PipelinePoint {
+    let encode_channel = |c: f32| -> f32 {
+      let c = (c / Self::MAX_COLOR).max(0.0).min(1.0);
+      let encoded = if c <= 0.0031308 {
+        12.92 * c
+      } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+      };
+      encoded * Self::MAX_COLOR
+    };
+
     PipelinePoint {
-      x: x,
-      y: y,
-      r: red,
-      g: 0.0,
-      b: 0.0,
-      is_blank: false,
+      x: self.x,
+      y: self.y,
+      r: encode_channel(self.r),
+      g: encode_channel(self.g),
+      b: encode_channel(self.b),
+      is_blank: self.is_blank,
     }
   }
 
-  /// PipelinePoint CTOR.
-  /// Sets only the green color channel.
-  pub fn xy_green(x: f32, y: f32, green: f32) -> PipelinePoint {
+  /// Gamma-decode the color channels from sRGB space into linear light, the
+  /// inverse of `encode_srgb`. Channels are normalized by `MAX_COLOR`,
+  /// clamped to `[0, 1]`, passed through the standard sRGB inverse transfer
+  /// function, and scaled back. Position and `is_blank` are left untouched.
+  ///
+  /// Requires the `std` feature: the inverse transfer function's exponent
+  /// is not available on `core::f32`.
+  #[cfg(feature = "std")]
+  pub fn decode_srgb(&self) -> PipelinePoint {
+    let decode_channel = |c: f32| -> f32 {
+      let c = (c / Self::MAX_COLOR).max(0.0).min(1.0);
+      let decoded = if c <= 0.04045 {
+        c / 12.92
+      } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+      };
+      decoded * Self::MAX_COLOR
+    };
+
     PipelinePoint {
-      x: x,
-      y: y,
-      r: 0.0,
-      g: green,
-      b: 0.0,
-      is_blank: false,
+      x: self.x,
+      y: self.y,
+      r: decode_channel(self.r),
+      g: decode_channel(self.g),
+      b: decode_channel(self.b),
+      is_blank: self.is_blank,
     }
   }
 
+  /// Gamma-encode into sRGB space and narrow into a SimplePoint.
+  ///
+  /// Requires the `std` feature (see `encode_srgb`).
+  #[cfg(feature = "std")]
+  pub fn into_simple_pt_gamma(&self) -> SimplePoint {
+    self.encode_srgb().into_simple_pt()
+  }
+
   /// PipelinePoint CTOR.
-  /// Sets only the blue color channel.
-  pub fn xy_blue(x: f32, y: f32, blue: f32) -> PipelinePoint {
+  /// Builds a point from HSV color components instead of RGB.
+  /// `h` is hue in degrees (wrapped into `[0, 360)`), `s` is saturation and
+  /// `v` is value, both in `[0, 1]`.
+  ///
+  /// Requires the `std` feature: hue wrapping uses `f32::rem_euclid`, which
+  /// is not available on `core::f32`.
+  #[cfg(feature = "std")]
+  pub fn with_hsv(x: f32, y: f32, h: f32, s: f32, v: f32) -> PipelinePoint {
+    let h = h.rem_euclid(360.0);
+    let s = s.max(0.0).min(1.0);
+    let v = v.max(0.0).min(1.0);
+
+    let c = v * s;
+    let h_prime = h / 60.0;
+    let x_comp = c * (1.0 - (h_prime % 2.0 - 1.0).abs());
+    let m = v - c;
+
+    let (r1, g1, b1) = if h_prime < 1.0 {
+      (c, x_comp, 0.0)
+    } else if h_prime < 2.0 {
+      (x_comp, c, 0.0)
+    } else if h_prime < 3.0 {
+      (0.0, c, x_comp)
+    } else if h_prime < 4.0 {
+      (0.0, x_comp, c)
+    } else if h_prime < 5.0 {
+      (x_comp, 0.0, c)
+    } else {
+      (c, 0.0, x_comp)
+    };
+
+    PipelinePoint::xy_rgb(
+      x,
+      y,
+      (r1 + m) * Self::MAX_COLOR,
+      (g1 + m) * Self::MAX_COLOR,
+      (b1 + m) * Self::MAX_COLOR,
+    )
+  }
+
+  /// Convert this point's color into HSV, returning `(hue, saturation,
+  /// value)` with hue in degrees `[0, 360)` and saturation/value in
+  /// `[0, 1]`.
+  ///
+  /// Requires the `std` feature: hue wrapping uses `f32::rem_euclid`, which
+  /// is not available on `core::f32`.
+  #[cfg(feature = "std")]
+  pub fn to_hsv(&self) -> (f32, f32, f32) {
+    let r = self.r / Self::MAX_COLOR;
+    let g = self.g / Self::MAX_COLOR;
+    let b = self.b / Self::MAX_COLOR;
+
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+
+    let v = max;
+    let s = if v == 0.0 { 0.0 } else { delta / v };
+
+    let h = if delta == 0.0 {
+      0.0
+    } else if max == r {
+      60.0 * (((g - b) / delta) % 6.0)
+    } else if max == g {
+      60.0 * ((b - r) / delta + 2.0)
+    } else {
+      60.0 * ((r - g) / delta + 4.0)
+    };
+
+    let h = h.rem_euclid(360.0);
+
+    (h, s, v)
+  }
+
+  /// Rotate this point's color by `degrees` around the hue wheel, round
+  /// tripping through HSV. Position and `is_blank` are left untouched.
+  ///
+  /// Requires the `std` feature (see `to_hsv`/`with_hsv`).
+  #[cfg(feature = "std")]
+  pub fn shift_hue(&self, degrees: f32) -> PipelinePoint {
+    let (h, s, v) = self.to_hsv();
+    let mut shifted = PipelinePoint::with_hsv(self.x, self.y, h + degrees, s, v);
+    shifted.is_blank = self.is_blank;
+    shifted
+  }
+
+  /// Linearly interpolate between this point and another.
+  /// Each of x, y, r, g, b is interpolated independently as
+  /// `a + (b - a) * t`. `t` is clamped to `[0.0, 1.0]`.
+  ///
+  /// At the endpoints (`t <= 0.0` or `t >= 1.0`), `is_blank` is taken from
+  /// the corresponding endpoint; in between, the result is blanking only if
+  /// both endpoints are blanking.
+  pub fn lerp(&self, other: &PipelinePoint, t: f32) -> PipelinePoint {
+    let t = t.max(0.0).min(1.0);
+    let is_blank = if t <= 0.0 {
+      self.is_blank
+    } else if t >= 1.0 {
+      other.is_blank
+    } else {
+      self.is_blank && other.is_blank
+    };
+
     PipelinePoint {
-      x: x,
-      y: y,
-      r: 0.0,
-      g: 0.0,
-      b: blue,
-      is_blank: false,
+      x: self.x + (other.x - self.x) * t,
+      y: self.y + (other.y - self.y) * t,
+      r: self.r + (other.r - self.r) * t,
+      g: self.g + (other.g - self.g) * t,
+      b: self.b + (other.b - self.b) * t,
+      is_blank: is_blank,
     }
   }
 
-  /// PipelinePoint CTOR.
-  /// If set to on, the lasers are at full power. Otherwise, they're off.
-  /// An "off" point is *not* considered a blanking point.
-  pub fn xy_binary(x: f32, y: f32, on: bool) -> PipelinePoint {
-    let c = if on { Self::MAX_COLOR } else { 0.0 };
-    PipelinePoint::xy_rgb(x, y, c, c, c)
+  /// Returns an iterator of `steps` evenly spaced points between this point
+  /// and `other`, including both endpoints when `steps >= 2`. A single step
+  /// yields only this point, and zero steps yield nothing. Useful for filling
+  /// in a sparse segment of a galvo path in one call.
+  pub fn lerp_iter(&self, other: &PipelinePoint, steps: usize) -> LerpIter {
+    LerpIter {
+      start: *self,
+      end: *other,
+      steps: steps,
+      index: 0,
+    }
+  }
+}
+
+/// Iterator over evenly spaced points between two `PipelinePoint`s,
+/// produced by `PipelinePoint::lerp_iter`.
+pub struct LerpIter {
+  start: PipelinePoint,
+  end: PipelinePoint,
+  steps: usize,
+  index: usize,
+}
+
+impl Iterator for LerpIter {
+  type Item = PipelinePoint;
+
+  fn next(&mut self) -> Option<PipelinePoint> {
+    if self.index >= self.steps {
+      return None;
+    }
+
+    let t = if self.steps <= 1 {
+      0.0
+    } else {
+      self.index as f32 / (self.steps - 1) as f32
+    };
+
+    self.index += 1;
+    Some(self.start.lerp(&self.end, t))
   }
 }
 
@@ -328,6 +660,55 @@ mod tests {
     assert_eq!(true, pp.is_blank);
   }
 
+  #[test]
+  fn test_simplepoint_xy_hex() {
+    let pt = SimplePoint::xy_hex(10, 20, 0xff8000);
+    assert_eq!(10, pt.x);
+    assert_eq!(20, pt.y);
+    assert_eq!(255, pt.r);
+    assert_eq!(128, pt.g);
+    assert_eq!(0, pt.b);
+    assert_eq!(false, pt.is_blank);
+  }
+
+  #[test]
+  fn test_simplepoint_from_hex_str() {
+    let pt = SimplePoint::from_hex_str(10, 20, "#ff8000").unwrap();
+    assert_eq!(10, pt.x);
+    assert_eq!(20, pt.y);
+    assert_eq!(255, pt.r);
+    assert_eq!(128, pt.g);
+    assert_eq!(0, pt.b);
+
+    let pt = SimplePoint::from_hex_str(0, 0, "ff8000").unwrap();
+    assert_eq!(255, pt.r);
+    assert_eq!(128, pt.g);
+    assert_eq!(0, pt.b);
+
+    let pt = SimplePoint::from_hex_str(0, 0, "#f80").unwrap();
+    assert_eq!(255, pt.r);
+    assert_eq!(136, pt.g);
+    assert_eq!(0, pt.b);
+
+    assert!(SimplePoint::from_hex_str(0, 0, "#zzzzzz").is_none());
+    assert!(SimplePoint::from_hex_str(0, 0, "#ff80").is_none());
+  }
+
+  #[test]
+  fn test_simplepoint_from_hex_str_rejects_non_ascii() {
+    assert!(SimplePoint::from_hex_str(0, 0, "中").is_none());
+    assert!(SimplePoint::from_hex_str(0, 0, "#中文中").is_none());
+  }
+
+  #[test]
+  fn test_simplepoint_as_hex() {
+    let pt = SimplePoint::xy_rgb(0, 0, 255, 128, 0);
+    assert_eq!(0xff8000, pt.as_hex());
+
+    let pt = SimplePoint::xy_rgb(0, 0, 0, 0, 0);
+    assert_eq!(0, pt.as_hex());
+  }
+
   #[test]
   fn test_simplepoint_xy_luma() {
     let pt = SimplePoint::xy_luma(10, 20, 255);
@@ -391,6 +772,70 @@ mod tests {
     assert_eq!(false, pt.is_blank);
   }
 
+  #[test]
+  fn test_simplepoint_saturating_add() {
+    let a = SimplePoint::xy_rgb(10, 20, 200, 100, 50);
+    let b = SimplePoint::xy_rgb(0, 0, 100, 100, 10);
+
+    let sum = a.saturating_add(&b);
+    assert_eq!(10, sum.x);
+    assert_eq!(20, sum.y);
+    assert_eq!(255, sum.r);
+    assert_eq!(200, sum.g);
+    assert_eq!(60, sum.b);
+  }
+
+  #[test]
+  fn test_simplepoint_saturating_sub() {
+    let a = SimplePoint::xy_rgb(10, 20, 50, 100, 10);
+    let b = SimplePoint::xy_rgb(0, 0, 100, 50, 10);
+
+    let diff = a.saturating_sub(&b);
+    assert_eq!(10, diff.x);
+    assert_eq!(20, diff.y);
+    assert_eq!(0, diff.r);
+    assert_eq!(50, diff.g);
+    assert_eq!(0, diff.b);
+  }
+
+  #[cfg(feature = "std")]
+  #[test]
+  fn test_simplepoint_scale() {
+    let pt = SimplePoint::xy_rgb(10, 20, 100, 200, 255);
+
+    let scaled = pt.scale(0.5);
+    assert_eq!(10, scaled.x);
+    assert_eq!(20, scaled.y);
+    assert_eq!(50, scaled.r);
+    assert_eq!(100, scaled.g);
+    assert_eq!(128, scaled.b);
+
+    let clamped = pt.scale(2.0);
+    assert_eq!(200, clamped.r);
+    assert_eq!(255, clamped.g);
+    assert_eq!(255, clamped.b);
+  }
+
+  #[cfg(feature = "std")]
+  #[test]
+  fn test_simplepoint_blend() {
+    let a = SimplePoint::xy_rgb(10, 20, 0, 0, 0);
+    let b = SimplePoint::xy_rgb(0, 0, 255, 255, 255);
+
+    let mid = a.blend(&b, 0.5);
+    assert_eq!(10, mid.x);
+    assert_eq!(20, mid.y);
+    assert_eq!(128, mid.r);
+    assert_eq!(128, mid.g);
+    assert_eq!(128, mid.b);
+
+    let start = a.blend(&b, 0.0);
+    assert_eq!(0, start.r);
+
+    let end = a.blend(&b, 1.0);
+    assert_eq!(255, end.r);
+  }
+
   #[test]
   fn test_pipelinepoint_xy_rgb() {
     let pt = PipelinePoint::xy_rgb(100.0, -100.0, 1.0, 200.0, 220.0);
@@ -413,6 +858,17 @@ mod tests {
     assert_eq!(true, pt.is_blank);
   }
 
+  #[test]
+  fn test_pipelinepoint_xy_hex() {
+    let pt = PipelinePoint::xy_hex(10.0, 20.0, 0xff8000);
+    assert_eq!(10.0, pt.x);
+    assert_eq!(20.0, pt.y);
+    assert_eq!(255.0, pt.r);
+    assert_eq!(128.0, pt.g);
+    assert_eq!(0.0, pt.b);
+    assert_eq!(false, pt.is_blank);
+  }
+
   #[test]
   fn test_pipelinepoint_into_simple_pt() {
     let pp = PipelinePoint::xy_rgb(100.0, -100.0, 1.0, 200.0, 240.0);
@@ -434,6 +890,46 @@ mod tests {
     assert_eq!(true, sp.is_blank);
   }
 
+  #[cfg(feature = "std")]
+  #[test]
+  fn test_pipelinepoint_srgb_round_trip() {
+    let pp = PipelinePoint::xy_rgb(10.0, 20.0, 1.0, 128.0, 255.0);
+
+    let round_tripped = pp.encode_srgb().decode_srgb();
+    assert!((pp.r - round_tripped.r).abs() < 0.01);
+    assert!((pp.g - round_tripped.g).abs() < 0.01);
+    assert!((pp.b - round_tripped.b).abs() < 0.01);
+    assert_eq!(pp.x, round_tripped.x);
+    assert_eq!(pp.y, round_tripped.y);
+    assert_eq!(pp.is_blank, round_tripped.is_blank);
+  }
+
+  #[cfg(feature = "std")]
+  #[test]
+  fn test_pipelinepoint_encode_srgb_endpoints() {
+    let black = PipelinePoint::xy_rgb(0.0, 0.0, 0.0, 0.0, 0.0);
+    let encoded = black.encode_srgb();
+    assert_eq!(0.0, encoded.r);
+
+    let white = PipelinePoint::xy_rgb(0.0, 0.0, 255.0, 255.0, 255.0);
+    let encoded = white.encode_srgb();
+    assert!((encoded.r - 255.0).abs() < 0.01);
+  }
+
+  #[cfg(feature = "std")]
+  #[test]
+  fn test_pipelinepoint_into_simple_pt_gamma() {
+    let pp = PipelinePoint::xy_rgb(10.0, 20.0, 0.0, 128.0, 255.0);
+    let sp = pp.into_simple_pt_gamma();
+    let expected = pp.encode_srgb().into_simple_pt();
+
+    assert_eq!(expected.x, sp.x);
+    assert_eq!(expected.y, sp.y);
+    assert_eq!(expected.r, sp.r);
+    assert_eq!(expected.g, sp.g);
+    assert_eq!(expected.b, sp.b);
+  }
+
   #[test]
   fn test_pipelinepoint_xy_luma() {
     let pt = PipelinePoint::xy_luma(10.0, 20.0, 255.0);
@@ -497,6 +993,185 @@ mod tests {
     assert_eq!(false, pt.is_blank);
   }
 
+  #[cfg(feature = "std")]
+  #[test]
+  fn test_pipelinepoint_to_hsv_primaries() {
+    let red = PipelinePoint::xy_rgb(0.0, 0.0, 255.0, 0.0, 0.0);
+    let (h, s, v) = red.to_hsv();
+    assert_eq!(0.0, h);
+    assert_eq!(1.0, s);
+    assert_eq!(1.0, v);
+
+    let green = PipelinePoint::xy_rgb(0.0, 0.0, 0.0, 255.0, 0.0);
+    let (h, s, v) = green.to_hsv();
+    assert_eq!(120.0, h);
+    assert_eq!(1.0, s);
+    assert_eq!(1.0, v);
+
+    let blue = PipelinePoint::xy_rgb(0.0, 0.0, 0.0, 0.0, 255.0);
+    let (h, s, v) = blue.to_hsv();
+    assert_eq!(240.0, h);
+    assert_eq!(1.0, s);
+    assert_eq!(1.0, v);
+  }
+
+  #[cfg(feature = "std")]
+  #[test]
+  fn test_pipelinepoint_to_hsv_achromatic() {
+    let gray = PipelinePoint::xy_rgb(0.0, 0.0, 128.0, 128.0, 128.0);
+    let (h, s, v) = gray.to_hsv();
+    assert_eq!(0.0, h);
+    assert_eq!(0.0, s);
+    assert!((v - 128.0 / 255.0).abs() < 0.001);
+
+    let black = PipelinePoint::xy_rgb(0.0, 0.0, 0.0, 0.0, 0.0);
+    let (h, s, v) = black.to_hsv();
+    assert_eq!(0.0, h);
+    assert_eq!(0.0, s);
+    assert_eq!(0.0, v);
+  }
+
+  #[cfg(feature = "std")]
+  #[test]
+  fn test_pipelinepoint_with_hsv_and_round_trip() {
+    let pt = PipelinePoint::with_hsv(10.0, 20.0, 300.0, 0.5, 0.8);
+    assert_eq!(10.0, pt.x);
+    assert_eq!(20.0, pt.y);
+    assert_eq!(false, pt.is_blank);
+
+    let (h, s, v) = pt.to_hsv();
+    assert!((h - 300.0).abs() < 0.01);
+    assert!((s - 0.5).abs() < 0.01);
+    assert!((v - 0.8).abs() < 0.01);
+  }
+
+  #[cfg(feature = "std")]
+  #[test]
+  fn test_pipelinepoint_shift_hue() {
+    let red = PipelinePoint::xy_rgb(5.0, 6.0, 255.0, 0.0, 0.0);
+    let shifted = red.shift_hue(120.0);
+
+    let (h, _, _) = shifted.to_hsv();
+    assert!((h - 120.0).abs() < 0.01);
+    assert_eq!(5.0, shifted.x);
+    assert_eq!(6.0, shifted.y);
+    assert_eq!(false, shifted.is_blank);
+
+    let blank = PipelinePoint::xy_blank(0.0, 0.0);
+    let shifted = blank.shift_hue(90.0);
+    assert_eq!(true, shifted.is_blank);
+  }
+
+  #[test]
+  fn test_pipelinepoint_lerp() {
+    let a = PipelinePoint::xy_rgb(0.0, 0.0, 0.0, 0.0, 0.0);
+    let b = PipelinePoint::xy_rgb(10.0, 20.0, 100.0, 200.0, 50.0);
+
+    let mid = a.lerp(&b, 0.5);
+    assert_eq!(5.0, mid.x);
+    assert_eq!(10.0, mid.y);
+    assert_eq!(50.0, mid.r);
+    assert_eq!(100.0, mid.g);
+    assert_eq!(25.0, mid.b);
+    assert_eq!(false, mid.is_blank);
+
+    let start = a.lerp(&b, 0.0);
+    assert_eq!(a.x, start.x);
+    assert_eq!(a.y, start.y);
+
+    let end = a.lerp(&b, 1.0);
+    assert_eq!(b.x, end.x);
+    assert_eq!(b.y, end.y);
+  }
+
+  #[test]
+  fn test_pipelinepoint_lerp_clamps_t() {
+    let a = PipelinePoint::xy_rgb(0.0, 0.0, 0.0, 0.0, 0.0);
+    let b = PipelinePoint::xy_rgb(10.0, 0.0, 0.0, 0.0, 0.0);
+
+    let below = a.lerp(&b, -1.0);
+    assert_eq!(0.0, below.x);
+
+    let above = a.lerp(&b, 2.0);
+    assert_eq!(10.0, above.x);
+  }
+
+  #[test]
+  fn test_pipelinepoint_lerp_blank_rule() {
+    let lit = PipelinePoint::xy_rgb(0.0, 0.0, 255.0, 0.0, 0.0);
+    let blank = PipelinePoint::xy_blank(10.0, 0.0);
+
+    let mid = lit.lerp(&blank, 0.5);
+    assert_eq!(false, mid.is_blank);
+
+    let arrived = lit.lerp(&blank, 1.0);
+    assert_eq!(true, arrived.is_blank);
+
+    let both_blank = blank.lerp(&blank, 0.5);
+    assert_eq!(true, both_blank.is_blank);
+  }
+
+  #[test]
+  #[cfg(feature = "std")]
+  fn test_pipelinepoint_lerp_iter() {
+    let a = PipelinePoint::xy_rgb(0.0, 0.0, 0.0, 0.0, 0.0);
+    let b = PipelinePoint::xy_rgb(10.0, 0.0, 0.0, 0.0, 0.0);
+
+    let points: Vec<PipelinePoint> = a.lerp_iter(&b, 3).collect();
+    assert_eq!(3, points.len());
+    assert_eq!(0.0, points[0].x);
+    assert_eq!(5.0, points[1].x);
+    assert_eq!(10.0, points[2].x);
+  }
+
+  #[test]
+  #[cfg(feature = "std")]
+  fn test_pipelinepoint_lerp_iter_single_step() {
+    let a = PipelinePoint::xy_rgb(0.0, 0.0, 0.0, 0.0, 0.0);
+    let b = PipelinePoint::xy_rgb(10.0, 0.0, 0.0, 0.0, 0.0);
+
+    let points: Vec<PipelinePoint> = a.lerp_iter(&b, 1).collect();
+    assert_eq!(1, points.len());
+    assert_eq!(0.0, points[0].x);
+
+    let points: Vec<PipelinePoint> = a.lerp_iter(&b, 0).collect();
+    assert_eq!(0, points.len());
+  }
+
+  #[test]
+  fn test_point_convert_widens_precision() {
+    let sp = SimplePoint::xy_rgb(10, -20, 1, 128, 255);
+    let pp: PipelinePoint = sp.convert();
+
+    assert_eq!(10.0, pp.x);
+    assert_eq!(-20.0, pp.y);
+    assert_eq!(1.0, pp.r);
+    assert_eq!(128.0, pp.g);
+    assert_eq!(255.0, pp.b);
+    assert_eq!(false, pp.is_blank);
+  }
+
+  #[test]
+  fn test_point_convert_narrows_precision() {
+    let pp = PipelinePoint::xy_blank(10.0, -20.0);
+    let sp: SimplePoint = pp.convert();
+
+    assert_eq!(10, sp.x);
+    assert_eq!(-20, sp.y);
+    assert_eq!(true, sp.is_blank);
+  }
+
+  #[test]
+  fn test_point_custom_instantiation() {
+    let pt = Point::<i32, u16>::xy_rgb(1_000_000, -1_000_000, 1, 32_000, 65_535);
+    assert_eq!(1_000_000, pt.x);
+    assert_eq!(-1_000_000, pt.y);
+    assert_eq!(1, pt.r);
+    assert_eq!(32_000, pt.g);
+    assert_eq!(65_535, pt.b);
+    assert_eq!(false, pt.is_blank);
+  }
+
   // This simply tests that f32 is enough for our needs.
   // It's not really necessary to retain these tests, but it's a good
   // demonstration.
@@ -511,4 +1186,26 @@ mod tests {
       assert_eq!(n, convert(n));
     }
   }
+
+  #[test]
+  #[cfg(feature = "serde")]
+  fn test_simplepoint_serde_round_trip() {
+    let pt = SimplePoint::xy_rgb(1, -2, 3, 4, 5);
+    let json = serde_json::to_string(&pt).unwrap();
+
+    assert!(json.contains("\"x\":1"));
+    assert!(json.contains("\"y\":-2"));
+    assert!(json.contains("\"r\":3"));
+    assert!(json.contains("\"g\":4"));
+    assert!(json.contains("\"b\":5"));
+    assert!(json.contains("\"is_blank\":false"));
+
+    let back: SimplePoint = serde_json::from_str(&json).unwrap();
+    assert_eq!(pt.x, back.x);
+    assert_eq!(pt.y, back.y);
+    assert_eq!(pt.r, back.r);
+    assert_eq!(pt.g, back.g);
+    assert_eq!(pt.b, back.b);
+    assert_eq!(pt.is_blank, back.is_blank);
+  }
 }